@@ -1,4 +1,5 @@
 use {
+    serde::{Deserialize, Serialize},
     std::{
         fmt::{self, Display},
         time::{SystemTime, UNIX_EPOCH}
@@ -23,16 +24,19 @@ pub trait GenericItem{
     fn get_sell_in(&self) -> i32;
     fn set_sell_in(&mut self, sell_in: i32);
 
+    // No-op by default; types that keep an audit trail (e.g. `ItemV2`) override this.
+    fn record_quality_change(&mut self, _old_quality: i32, _new_quality: i32, _sell_in: i32) {}
+
     fn update_quality(&mut self) {
         let behavior = self.get_behavior();
         let new_quality = match behavior {
             QualityBehavior::Decrease { rate, min_quality, max_quality } => {
-                let actual_rate = if self.get_sell_in() <= 0 { rate * 2 } else { rate };
-                Some((self.get_quality() - actual_rate).clamp(min_quality, max_quality))
+                let actual_rate = if self.get_sell_in() <= 0 { rate.saturating_mul(2) } else { rate };
+                Some(self.get_quality().saturating_sub(actual_rate).clamp(min_quality, max_quality))
             },
             QualityBehavior::Increase { rate, min_quality, max_quality } => {
                 // Should we decrease or keep increasing after concert ?
-                Some((self.get_quality() + rate).clamp(min_quality, max_quality))
+                Some(self.get_quality().saturating_add(rate).clamp(min_quality, max_quality))
             },
             QualityBehavior::TimeSensitiveIncrease { 
                 min_quality, 
@@ -48,7 +52,7 @@ pub trait GenericItem{
                         .min_by_key(|t| t.days_left)
                         .map_or(1, |t| t.increase_rate);
                     
-                    Some((self.get_quality() + increase).clamp(min_quality, max_quality))
+                    Some(self.get_quality().saturating_add(increase).clamp(min_quality, max_quality))
                 }
             },
             QualityBehavior::Constant => {
@@ -57,8 +61,12 @@ pub trait GenericItem{
         };
 
         if let Some(quality) = new_quality {
+            let old_quality = self.get_quality();
             self.set_quality(quality);
-            self.set_sell_in(self.get_sell_in() - 1)
+            self.set_sell_in(self.get_sell_in() - 1);
+            if quality != old_quality {
+                self.record_quality_change(old_quality, quality, self.get_sell_in());
+            }
         };
 
     }
@@ -114,12 +122,24 @@ impl Display for Item {
     }
 }
 
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct QualityChange {
+    pub timestamp: i64,
+    pub old_quality: i32,
+    pub new_quality: i32,
+    pub sell_in: i32,
+}
+
+#[derive(Serialize, Deserialize)]
 pub struct ItemV2 {
     pub name: String,
     pub sell_in: i32,
     pub quality: i32,
     pub behavior: QualityBehavior,
-    pub timestamp: i64
+    #[serde(default = "get_unix_timestamp")]
+    pub timestamp: i64,
+    #[serde(default)]
+    history: Vec<QualityChange>,
 }
 impl ItemV2 {
     pub fn new(
@@ -133,7 +153,8 @@ impl ItemV2 {
             sell_in,
             quality,
             behavior,
-            timestamp: get_unix_timestamp()
+            timestamp: get_unix_timestamp(),
+            history: Vec::new(),
         }
     }
 
@@ -145,6 +166,10 @@ impl ItemV2 {
             item.get_behavior(),
         )
     }
+
+    pub fn history(&self) -> &[QualityChange] {
+        &self.history
+    }
 }
 
 impl GenericItem for ItemV2 {
@@ -168,6 +193,15 @@ impl GenericItem for ItemV2 {
     fn set_sell_in(&mut self, sell_in: i32) {
         self.sell_in = sell_in;
     }
+
+    fn record_quality_change(&mut self, old_quality: i32, new_quality: i32, sell_in: i32) {
+        self.history.push(QualityChange {
+            timestamp: self.timestamp,
+            old_quality,
+            new_quality,
+            sell_in,
+        });
+    }
 }
 impl Display for ItemV2 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -175,6 +209,67 @@ impl Display for ItemV2 {
     }
 }
 
+pub enum ItemKind {
+    V1(Item),
+    V2(ItemV2),
+}
+
+impl GenericItem for ItemKind {
+    fn get_behavior(&self) -> QualityBehavior {
+        match self {
+            ItemKind::V1(item) => item.get_behavior(),
+            ItemKind::V2(item) => item.get_behavior(),
+        }
+    }
+
+    fn get_quality(&self) -> i32 {
+        match self {
+            ItemKind::V1(item) => item.get_quality(),
+            ItemKind::V2(item) => item.get_quality(),
+        }
+    }
+
+    fn set_quality(&mut self, quality: i32) {
+        match self {
+            ItemKind::V1(item) => item.set_quality(quality),
+            ItemKind::V2(item) => item.set_quality(quality),
+        }
+    }
+
+    fn get_sell_in(&self) -> i32 {
+        match self {
+            ItemKind::V1(item) => item.get_sell_in(),
+            ItemKind::V2(item) => item.get_sell_in(),
+        }
+    }
+
+    fn set_sell_in(&mut self, sell_in: i32) {
+        match self {
+            ItemKind::V1(item) => item.set_sell_in(sell_in),
+            ItemKind::V2(item) => item.set_sell_in(sell_in),
+        }
+    }
+
+    fn record_quality_change(&mut self, old_quality: i32, new_quality: i32, sell_in: i32) {
+        match self {
+            ItemKind::V1(item) => item.record_quality_change(old_quality, new_quality, sell_in),
+            ItemKind::V2(item) => item.record_quality_change(old_quality, new_quality, sell_in),
+        }
+    }
+}
+
+impl From<Item> for ItemKind {
+    fn from(item: Item) -> Self {
+        ItemKind::V1(item)
+    }
+}
+
+impl From<ItemV2> for ItemKind {
+    fn from(item: ItemV2) -> Self {
+        ItemKind::V2(item)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use {
@@ -324,4 +419,52 @@ mod tests {
         item.update_quality();
         assert_eq!(item.quality, 38);
     }
+
+    #[test]
+    fn test_itemv2_json_round_trip() {
+        let behavior = QualityBehavior::backstage_passes_items();
+        let mut original = ItemV2::new("Backstage passes to a TAFKAL80ETC concert", 10, 20, behavior.clone());
+        let mut reloaded: ItemV2 = serde_json::from_str(&serde_json::to_string(&original).unwrap()).unwrap();
+
+        original.update_quality();
+        reloaded.update_quality();
+
+        assert_eq!(original.quality, reloaded.quality);
+        assert_eq!(original.sell_in, reloaded.sell_in);
+        assert_eq!(original.behavior, reloaded.behavior);
+    }
+
+    #[test]
+    fn test_itemv2_toml_round_trip() {
+        let behavior = QualityBehavior::new_time_sensitive_default_quality(vec![
+            TimeSensitiveIncreaseQualityBehaviorThresholds { days_left: 10, increase_rate: 2 },
+        ]);
+        let mut original = ItemV2::new("Custom Item", 12, 10, behavior);
+        let mut reloaded: ItemV2 = toml::from_str(&toml::to_string(&original).unwrap()).unwrap();
+
+        original.update_quality();
+        reloaded.update_quality();
+
+        assert_eq!(original.quality, reloaded.quality);
+        assert_eq!(original.sell_in, reloaded.sell_in);
+    }
+
+    #[test]
+    fn test_itemv2_history_tracks_threshold_jumps() {
+        let behavior = QualityBehavior::backstage_passes_items();
+        let mut item = ItemV2::new("Backstage passes to a TAFKAL80ETC concert", 11, 20, behavior);
+
+        for _ in 0..3 {
+            item.update_quality();
+        }
+
+        let transitions: Vec<(i32, i32, i32)> = item
+            .history()
+            .iter()
+            .map(|change| (change.old_quality, change.new_quality, change.sell_in))
+            .collect();
+
+        assert_eq!(transitions, vec![(20, 21, 10), (21, 23, 9), (23, 25, 8)]);
+        assert!(item.history().iter().all(|change| change.timestamp > 0));
+    }
 }
\ No newline at end of file
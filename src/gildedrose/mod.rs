@@ -1,16 +1,16 @@
 pub mod quality_behavior;
 mod item;
 
+use std::{fmt, fs, io, path::Path, thread};
+use serde::Deserialize;
+
 #[allow(unused_imports)]
-pub use item::{GenericItem, Item, ItemV2};
+pub use item::{GenericItem, Item, ItemKind, ItemV2, QualityChange};
 
 // We could use dynamic dispatch to work with both Item and ItemV2 at the same time
 // We could also use reflection (not implemented in rust, we would need to implement it ourselves using a derive macro or use reflection crate)
-// We could also use a enum
-// enum ItemType {
-//     V1(Item),
-//     V2(ItemV2),
-// }
+// Heterogeneous inventories are covered by `ItemKind` (see item.rs), which
+// delegates `GenericItem` to whichever variant it wraps.
 
 pub struct GildedRose<T: GenericItem> {
     pub items: Vec<T>,
@@ -25,15 +25,289 @@ impl<T: GenericItem> GildedRose<T> {
     pub fn update_quality(&mut self) {
         self.items.iter_mut().for_each(|item| item.update_quality())
     }
-  
+
+    pub fn items_with_behavior<P>(&self, mut pred: P) -> impl Iterator<Item = &T>
+    where
+        P: FnMut(&quality_behavior::QualityBehavior) -> bool,
+    {
+        self.items.iter().filter(move |item| pred(&item.get_behavior()))
+    }
+
+    pub fn expired(&self) -> impl Iterator<Item = &T> {
+        self.items.iter().filter(|item| item.get_sell_in() <= 0)
+    }
+
+}
+
+impl<'a, T: GenericItem> IntoIterator for &'a GildedRose<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl<'a, T: GenericItem> IntoIterator for &'a mut GildedRose<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter_mut()
+    }
+}
+
+impl<T: GenericItem + Send> GildedRose<T> {
+    // Each item's update is independent, so chunks never alias the same element and no `unsafe` is needed.
+    pub fn update_quality_parallel(&mut self) {
+        let thread_count = thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+        let chunk_size = self.items.len().div_ceil(thread_count).max(1);
+
+        thread::scope(|scope| {
+            for chunk in self.items.chunks_mut(chunk_size) {
+                scope.spawn(move || {
+                    chunk.iter_mut().for_each(|item| item.update_quality())
+                });
+            }
+        });
+    }
+}
+
+#[derive(Deserialize)]
+struct ItemCatalog {
+    items: Vec<ItemV2>,
+}
+
+#[derive(Debug)]
+pub enum GildedRoseError {
+    Io(io::Error),
+    Toml(toml::de::Error),
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for GildedRoseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GildedRoseError::Io(err) => write!(f, "failed to read inventory file: {err}"),
+            GildedRoseError::Toml(err) => write!(f, "failed to parse TOML inventory: {err}"),
+            GildedRoseError::Json(err) => write!(f, "failed to parse JSON inventory: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for GildedRoseError {}
+
+impl From<io::Error> for GildedRoseError {
+    fn from(err: io::Error) -> Self {
+        GildedRoseError::Io(err)
+    }
+}
+
+impl From<toml::de::Error> for GildedRoseError {
+    fn from(err: toml::de::Error) -> Self {
+        GildedRoseError::Toml(err)
+    }
+}
+
+impl From<serde_json::Error> for GildedRoseError {
+    fn from(err: serde_json::Error) -> Self {
+        GildedRoseError::Json(err)
+    }
+}
+
+impl GildedRose<ItemV2> {
+    pub fn from_toml(path: impl AsRef<Path>) -> Result<Self, GildedRoseError> {
+        let contents = fs::read_to_string(path)?;
+        let catalog: ItemCatalog = toml::from_str(&contents)?;
+        Ok(GildedRose::new(catalog.items))
+    }
+
+    pub fn from_json(path: impl AsRef<Path>) -> Result<Self, GildedRoseError> {
+        let contents = fs::read_to_string(path)?;
+        let catalog: ItemCatalog = serde_json::from_str(&contents)?;
+        Ok(GildedRose::new(catalog.items))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::gildedrose::{
         quality_behavior::{QualityBehavior, TimeSensitiveIncreaseQualityBehaviorThresholds},
-        Item, ItemV2, GildedRose
+        GenericItem, Item, ItemKind, ItemV2, GildedRose
     };
+    use std::fs;
+
+    // Unique per process and call site so concurrent test runs don't race on the same file.
+    fn unique_temp_path(label: &str, extension: &str) -> std::path::PathBuf {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        std::env::temp_dir().join(format!(
+            "gildedrose_test_{label}_{}_{n}.{extension}",
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn test_gilded_rose_mixed_item_kinds() {
+        let items = vec![
+            ItemKind::from(Item::new("Aged Brie", 5, 20)),
+            ItemKind::from(ItemV2::new("Custom Item", 5, 20, QualityBehavior::conjured_items())),
+        ];
+
+        let mut rose = GildedRose::new(items);
+        rose.update_quality();
+
+        assert_eq!(rose.items[0].get_quality(), 21);
+        assert_eq!(rose.items[0].get_sell_in(), 4);
+
+        assert_eq!(rose.items[1].get_quality(), 18);
+        assert_eq!(rose.items[1].get_sell_in(), 4);
+    }
+
+    fn generate_large_inventory(size: usize) -> Vec<Item> {
+        (0..size)
+            .map(|i| match i % 4 {
+                0 => Item::new("Aged Brie", 10, 20),
+                1 => Item::new("Backstage passes to a TAFKAL80ETC concert", 10, 20),
+                2 => Item::new("Conjured", 10, 20),
+                _ => Item::new("Normal Item", 10, 20),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_update_quality_parallel_matches_sequential() {
+        let mut sequential = GildedRose::new(generate_large_inventory(200_000));
+        let mut parallel = GildedRose::new(generate_large_inventory(200_000));
+
+        sequential.update_quality();
+        parallel.update_quality_parallel();
+
+        for (seq_item, par_item) in sequential.items.iter().zip(parallel.items.iter()) {
+            assert_eq!(seq_item.quality, par_item.quality);
+            assert_eq!(seq_item.sell_in, par_item.sell_in);
+        }
+    }
+
+    #[test]
+    fn test_into_iterator_after_update() {
+        let items = vec![
+            Item::new("Normal Item", 1, 49),
+            Item::new("Aged Brie", 1, 49),
+        ];
+        let mut rose = GildedRose::new(items);
+        rose.update_quality();
+
+        let names_and_qualities: Vec<(&str, i32)> = (&rose)
+            .into_iter()
+            .map(|item| (item.name.as_str(), item.quality))
+            .collect();
+
+        assert_eq!(
+            names_and_qualities,
+            vec![("Normal Item", 48), ("Aged Brie", 50)]
+        );
+
+        for item in &mut rose {
+            item.quality = 0;
+        }
+        assert!(rose.items.iter().all(|item| item.quality == 0));
+    }
+
+    #[test]
+    fn test_items_with_behavior_and_expired() {
+        let items = vec![
+            Item::new("Aged Brie", 0, 20),
+            Item::new("Normal Item", 5, 20),
+            Item::new("Normal Item", 0, 20),
+        ];
+        let rose = GildedRose::new(items);
+
+        let increasing: Vec<&Item> = rose
+            .items_with_behavior(|behavior| *behavior == QualityBehavior::standard_increase())
+            .collect();
+        assert_eq!(increasing.len(), 1);
+        assert_eq!(increasing[0].name, "Aged Brie");
+
+        let expired: Vec<&Item> = rose.expired().collect();
+        assert_eq!(expired.len(), 2);
+    }
+
+    #[test]
+    fn test_gilded_rose_from_toml_round_trip() {
+        let path = unique_temp_path("catalog", "toml");
+        let contents = r#"
+            [[items]]
+            name = "Aged Brie"
+            sell_in = 10
+            quality = 20
+
+            [items.behavior]
+            type = "Increase"
+            rate = 1
+            min_quality = 0
+            max_quality = 50
+        "#;
+        fs::write(&path, contents).unwrap();
+
+        let rose = GildedRose::<ItemV2>::from_toml(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(rose.items.len(), 1);
+        assert_eq!(rose.items[0].name, "Aged Brie");
+        assert_eq!(rose.items[0].behavior, QualityBehavior::standard_increase());
+    }
+
+    #[test]
+    fn test_gilded_rose_from_json_round_trip() {
+        let path = unique_temp_path("catalog", "json");
+        let contents = r#"{
+            "items": [
+                {
+                    "name": "Custom Item",
+                    "sell_in": 15,
+                    "quality": 10,
+                    "behavior": { "type": "TimeSensitiveIncrease", "min_quality": 0, "max_quality": 100, "thresholds": [{"days_left": 15, "increase_rate": 1}], "drop_quality_after": 0 }
+                }
+            ]
+        }"#;
+        fs::write(&path, contents).unwrap();
+
+        let rose = GildedRose::<ItemV2>::from_json(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(rose.items.len(), 1);
+        assert_eq!(rose.items[0].name, "Custom Item");
+        assert_eq!(rose.items[0].quality, 10);
+    }
+
+    #[test]
+    fn test_gilded_rose_from_json_clamps_extreme_catalog_values() {
+        let path = unique_temp_path("extreme_catalog", "json");
+        let contents = format!(
+            r#"{{
+                "items": [
+                    {{
+                        "name": "Overflow Item",
+                        "sell_in": 10,
+                        "quality": {},
+                        "behavior": {{ "type": "Increase", "rate": {}, "min_quality": 0, "max_quality": 50 }}
+                    }}
+                ]
+            }}"#,
+            i32::MAX,
+            i32::MAX
+        );
+        fs::write(&path, &contents).unwrap();
+
+        let mut rose = GildedRose::<ItemV2>::from_json(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        rose.update_quality();
+
+        assert_eq!(rose.items[0].quality, 50);
+    }
 
     #[test]
     fn test_item_creation() {
@@ -1,10 +1,13 @@
-#[derive(Clone, PartialEq, Debug)]
-pub struct TimeSensitiveIncreaseQualityBehaviorThresholds { 
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct TimeSensitiveIncreaseQualityBehaviorThresholds {
     pub days_left: i32,
     pub increase_rate: i32,
 }
 
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
 pub enum QualityBehavior  {
     Constant,
     Decrease { rate: i32, min_quality: i32, max_quality: i32 },